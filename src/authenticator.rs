@@ -0,0 +1,158 @@
+//! Automatic token caching and refresh layered over [`crate::OAuth2Client`].
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::token_store::{StoredToken, TokenKey, TokenStore};
+
+/// Default window before actual expiry at which a cached token is refreshed
+/// rather than handed out.
+const DEFAULT_EXPIRY_SKEW: Duration = Duration::from_secs(60);
+
+/// Performs the token exchange needed to obtain a fresh access token.
+///
+/// Implementations typically hold the `oauth2` crate's `BasicClient`
+/// configuration together with an [`crate::OAuth2Client`] and perform a
+/// refresh-token exchange (or re-run the device flow when no refresh token
+/// is available) through the existing `AsyncHttpClient` path. An
+/// implementation built directly on [`crate::HttpInterface`] instead (with
+/// no `BasicClient` involved) can use [`crate::OAuth2HttpError::from_response`]
+/// to turn a non-success response into a typed `Self::Error`.
+///
+/// # Example
+///
+/// ```ignore
+/// use oauth2::{ClientId, ClientSecret, RefreshToken, TokenResponse, TokenUrl, basic::BasicClient};
+/// use oauth2_http_client::{OAuth2Client, StoredToken, TokenRefresher};
+///
+/// struct MyRefresher {
+///     client: BasicClient<..>,
+///     oauth2_client: OAuth2Client<MyHttpInterface>,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl TokenRefresher for MyRefresher {
+///     type Error = oauth2::RequestTokenError<..>;
+///
+///     async fn refresh(&self, refresh_token: Option<&str>) -> Result<StoredToken, Self::Error> {
+///         let refresh_token = refresh_token.expect("device flow re-run not shown");
+///         let response = self
+///             .client
+///             .exchange_refresh_token(&RefreshToken::new(refresh_token.to_string()))
+///             .request_async(&self.oauth2_client)
+///             .await?;
+///
+///         Ok(StoredToken::new(
+///             response.access_token().secret().clone(),
+///             response.refresh_token().map(|t| t.secret().clone()),
+///             response.expires_in().unwrap_or_default(),
+///         ))
+///     }
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait TokenRefresher {
+    /// The error type returned when the exchange fails.
+    type Error: std::fmt::Debug + Send + Sync + 'static;
+
+    /// Exchanges `refresh_token` for a fresh access token, or performs
+    /// whichever initial flow is configured when `refresh_token` is `None`.
+    async fn refresh(&self, refresh_token: Option<&str>) -> Result<StoredToken, Self::Error>;
+}
+
+/// Error returned by [`Authenticator::token`].
+#[derive(Debug)]
+pub enum AuthenticatorError<R, S> {
+    /// The token exchange performed by the [`TokenRefresher`] failed.
+    Refresh(R),
+    /// Reading from or writing to the [`TokenStore`] failed.
+    Store(S),
+}
+
+impl<R: fmt::Display, S: fmt::Display> fmt::Display for AuthenticatorError<R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthenticatorError::Refresh(err) => write!(f, "token refresh failed: {}", err),
+            AuthenticatorError::Store(err) => write!(f, "token store failed: {}", err),
+        }
+    }
+}
+
+impl<R, S> std::error::Error for AuthenticatorError<R, S>
+where
+    R: std::error::Error + 'static,
+    S: std::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AuthenticatorError::Refresh(err) => Some(err),
+            AuthenticatorError::Store(err) => Some(err),
+        }
+    }
+}
+
+/// Caches access tokens and refreshes them before they expire.
+///
+/// Every call to [`Authenticator::token`] checks `store` for a token that is
+/// still valid at least `expiry_skew` before its real expiry; on a miss it
+/// delegates to `refresher` to obtain a new one and persists the result.
+pub struct Authenticator<R, TS> {
+    refresher: R,
+    store: TS,
+    key: TokenKey,
+    expiry_skew: Duration,
+}
+
+impl<R, TS> Authenticator<R, TS>
+where
+    R: TokenRefresher,
+    TS: TokenStore,
+{
+    /// Creates an authenticator caching tokens for `key` in `store`,
+    /// refreshing them through `refresher` when missing or expiring.
+    pub fn new(refresher: R, store: TS, key: TokenKey) -> Self {
+        Self {
+            refresher,
+            store,
+            key,
+            expiry_skew: DEFAULT_EXPIRY_SKEW,
+        }
+    }
+
+    /// Overrides the default 60-second expiry skew.
+    pub fn with_expiry_skew(mut self, expiry_skew: Duration) -> Self {
+        self.expiry_skew = expiry_skew;
+        self
+    }
+
+    /// Returns a valid access token, transparently refreshing and caching a
+    /// new one if the cached token is missing or within `expiry_skew` of
+    /// expiring.
+    pub async fn token(&self) -> Result<StoredToken, AuthenticatorError<R::Error, TS::Error>> {
+        let cached = self
+            .store
+            .load(&self.key)
+            .await
+            .map_err(AuthenticatorError::Store)?;
+
+        if let Some(token) = &cached {
+            if token.is_valid(self.expiry_skew) {
+                return Ok(token.clone());
+            }
+        }
+
+        let refresh_token = cached.and_then(|token| token.refresh_token);
+        let refreshed = self
+            .refresher
+            .refresh(refresh_token.as_deref())
+            .await
+            .map_err(AuthenticatorError::Refresh)?;
+
+        self.store
+            .store(&self.key, refreshed.clone())
+            .await
+            .map_err(AuthenticatorError::Store)?;
+
+        Ok(refreshed)
+    }
+}
@@ -0,0 +1,34 @@
+//! Error types produced by [`crate::OAuth2Client`].
+
+use std::fmt;
+
+/// Error returned by [`crate::OAuth2Client`]'s `AsyncHttpClient` implementation.
+///
+/// Wraps the underlying [`crate::HttpInterface::Error`] while adding variants
+/// for conditions the wrapper itself detects, such as a request exceeding its
+/// configured deadline.
+#[derive(Debug)]
+pub enum OAuth2ClientError<E> {
+    /// The underlying `HttpInterface` failed to perform the request.
+    Interface(E),
+    /// The request did not complete before the configured timeout elapsed.
+    Timeout,
+}
+
+impl<E: fmt::Display> fmt::Display for OAuth2ClientError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OAuth2ClientError::Interface(err) => write!(f, "{}", err),
+            OAuth2ClientError::Timeout => write!(f, "request timed out"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for OAuth2ClientError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OAuth2ClientError::Interface(err) => Some(err),
+            OAuth2ClientError::Timeout => None,
+        }
+    }
+}
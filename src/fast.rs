@@ -0,0 +1,110 @@
+//! Allocation-free alternative to [`crate::HttpInterface`].
+//!
+//! [`crate::HttpInterface`] is built on `#[async_trait]`, so every call to
+//! `perform` boxes its future and dispatches through a vtable even when `HI`
+//! is a single, statically known type. [`FastHttpInterface`] uses a native
+//! `async fn` instead, so the compiler generates a concrete, inlinable
+//! future per implementation with no allocation on that hot path. The
+//! trade-off is that `FastHttpInterface` is not object-safe, so it can't be
+//! boxed as `dyn FastHttpInterface` the way `HttpInterface` can.
+//!
+//! [`FastOAuth2Client`] is the `oauth2`-compatible wrapper for this trait,
+//! mirroring [`crate::OAuth2Client`]. The `oauth2` crate's own
+//! `AsyncHttpClient::Future` is a plain associated type rather than an
+//! `async fn`, so `FastOAuth2Client::call` still needs one `Box::pin` at
+//! that boundary, but the extra boxing and dynamic dispatch that used to
+//! happen inside `perform` itself is gone.
+
+use std::future::Future;
+use std::pin::Pin;
+
+use oauth2::{AsyncHttpClient, HttpRequest, HttpResponse};
+
+/// Allocation-free counterpart to [`crate::HttpInterface`].
+///
+/// See the module documentation for the trade-offs versus the boxed,
+/// object-safe [`crate::HttpInterface`].
+pub trait FastHttpInterface {
+    /// The error type returned when a request fails.
+    type Error: std::fmt::Debug + Send + Sync + 'static;
+
+    /// Performs an HTTP request asynchronously, without boxing the future.
+    fn perform(
+        &self,
+        req: HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Self::Error>> + Send;
+}
+
+/// `oauth2`-compatible wrapper around a [`FastHttpInterface`].
+///
+/// Functionally equivalent to [`crate::OAuth2Client::new`], but for the
+/// allocation-free trait. It does not offer the timeout/interceptor
+/// layering built on [`crate::OAuth2Client`]; reach for that instead when
+/// those are needed and the extra boxing they imply is acceptable.
+///
+/// # Example
+///
+/// ```ignore
+/// use oauth2_http_client::{FastHttpInterface, FastOAuth2Client};
+///
+/// let oauth2_client = FastOAuth2Client::new(my_fast_http_interface);
+/// ```
+pub struct FastOAuth2Client<HI>
+where
+    HI: FastHttpInterface + Clone + Send + Sync + 'static,
+{
+    interface: HI,
+}
+
+impl<HI> FastOAuth2Client<HI>
+where
+    HI: FastHttpInterface + Clone + Send + Sync + 'static,
+{
+    /// Wraps `interface` for use with the `oauth2` crate.
+    pub fn new(interface: HI) -> Self {
+        Self { interface }
+    }
+}
+
+impl<'c, HI> AsyncHttpClient<'c> for FastOAuth2Client<HI>
+where
+    HI: FastHttpInterface + Clone + Send + Sync + 'static,
+    HI::Error: std::error::Error,
+{
+    type Error = HI::Error;
+
+    type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, Self::Error>> + Send + 'c>>;
+
+    fn call(&'c self, request: HttpRequest) -> Self::Future {
+        let interface = self.interface.clone();
+        Box::pin(async move { interface.perform(request).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Echo;
+
+    impl FastHttpInterface for Echo {
+        type Error = std::convert::Infallible;
+
+        async fn perform(&self, req: HttpRequest) -> Result<HttpResponse, Self::Error> {
+            Ok(http::Response::builder()
+                .status(200)
+                .body(req.into_body())
+                .unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn fast_oauth2_client_roundtrips_through_call() {
+        let client = FastOAuth2Client::new(Echo);
+        let request = http::Request::builder().body(b"hello".to_vec()).unwrap();
+
+        let response = client.call(request).await.unwrap();
+        assert_eq!(response.body(), b"hello");
+    }
+}
@@ -0,0 +1,144 @@
+//! A structured error capturing the status, selected headers, and body of a
+//! failed HTTP response.
+//!
+//! The `oauth2` crate already parses non-success token endpoint responses
+//! into its own [`oauth2::RequestTokenError::ServerResponse`], so
+//! [`crate::OAuth2Client`] intentionally leaves that response untouched.
+//! [`OAuth2HttpError`] is for code that sits outside that path, such as a
+//! custom [`crate::HttpInterface`], a [`crate::TokenRefresher`], or an
+//! [`crate::Interceptor`], and wants more than an opaque error string when a
+//! request fails: the status code, the `Retry-After`/`WWW-Authenticate`
+//! headers, and the standard OAuth2 `error`/`error_description` JSON body.
+
+use oauth2::HttpResponse;
+use serde::Deserialize;
+
+/// HTTP status codes considered transient and therefore safe to retry.
+///
+/// Shared with [`crate::RetryingInterface`] so the two stay in agreement
+/// about what "retryable" means.
+const RETRYABLE_STATUS_CODES: [u16; 6] = [429, 500, 501, 502, 503, 504];
+
+pub(crate) fn is_retryable_status(status: http::StatusCode) -> bool {
+    RETRYABLE_STATUS_CODES.contains(&status.as_u16())
+}
+
+/// The standard OAuth2 `error`/`error_description`/`error_uri` JSON fields,
+/// as defined by [RFC 6749 section 5.2](https://www.rfc-editor.org/rfc/rfc6749#section-5.2).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OAuth2ErrorBody {
+    /// The machine-readable error code, e.g. `invalid_grant`, `slow_down`,
+    /// or `authorization_pending`.
+    pub error: String,
+    /// A human-readable description of the error.
+    pub error_description: Option<String>,
+    /// A URI identifying a human-readable web page with error information.
+    pub error_uri: Option<String>,
+}
+
+/// A non-success HTTP response captured with enough detail to diagnose it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OAuth2HttpError {
+    /// The response's HTTP status code.
+    pub status: http::StatusCode,
+    /// The `Retry-After` header value, if present.
+    pub retry_after: Option<String>,
+    /// The `WWW-Authenticate` header value, if present.
+    pub www_authenticate: Option<String>,
+    /// The raw response body.
+    pub body: Vec<u8>,
+    /// The body parsed as the standard OAuth2 error JSON, if it matched.
+    pub parsed: Option<OAuth2ErrorBody>,
+}
+
+impl OAuth2HttpError {
+    /// Builds an `OAuth2HttpError` from `response` if its status is not a
+    /// success (2xx), or returns `None` otherwise.
+    pub fn from_response(response: &HttpResponse) -> Option<Self> {
+        if response.status().is_success() {
+            return None;
+        }
+
+        let header = |name: http::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Some(Self {
+            status: response.status(),
+            retry_after: header(http::header::RETRY_AFTER),
+            www_authenticate: header(http::header::WWW_AUTHENTICATE),
+            body: response.body().clone(),
+            parsed: serde_json::from_slice(response.body()).ok(),
+        })
+    }
+
+    /// Returns `true` if this error's status code is one that is generally
+    /// safe to retry (429 or 500–504).
+    pub fn is_retryable(&self) -> bool {
+        is_retryable_status(self.status)
+    }
+}
+
+impl std::fmt::Display for OAuth2HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.parsed {
+            Some(OAuth2ErrorBody {
+                error,
+                error_description: Some(description),
+                ..
+            }) => write!(f, "{} ({}): {}", self.status, error, description),
+            Some(OAuth2ErrorBody { error, .. }) => write!(f, "{} ({})", self.status, error),
+            None => write!(f, "{}", self.status),
+        }
+    }
+}
+
+impl std::error::Error for OAuth2HttpError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_response_returns_none_for_success() {
+        let response = http::Response::builder()
+            .status(200)
+            .body(Vec::new())
+            .unwrap();
+        assert_eq!(OAuth2HttpError::from_response(&response), None);
+    }
+
+    #[test]
+    fn from_response_parses_standard_error_body() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "error": "invalid_grant",
+            "error_description": "the refresh token expired",
+        }))
+        .unwrap();
+        let response = http::Response::builder()
+            .status(400)
+            .header("Retry-After", "5")
+            .body(body)
+            .unwrap();
+
+        let error = OAuth2HttpError::from_response(&response).unwrap();
+        assert_eq!(error.status, http::StatusCode::BAD_REQUEST);
+        assert_eq!(error.retry_after.as_deref(), Some("5"));
+        assert!(!error.is_retryable());
+        assert_eq!(error.parsed.unwrap().error, "invalid_grant");
+    }
+
+    #[test]
+    fn is_retryable_matches_retrying_interface() {
+        let response = http::Response::builder()
+            .status(503)
+            .body(Vec::new())
+            .unwrap();
+        let error = OAuth2HttpError::from_response(&response).unwrap();
+        assert!(error.is_retryable());
+    }
+}
@@ -0,0 +1,165 @@
+//! Request/response interceptor chain for [`crate::OAuth2Client`].
+//!
+//! Interceptors are ordered hooks that run before a request is sent (and may
+//! mutate the outgoing [`HttpRequest`]) and after the response comes back
+//! (for observability). Built-in interceptors cover default header
+//! injection and `tracing` span emission.
+
+use oauth2::{HttpRequest, HttpResponse};
+
+/// Header names masked by [`TracingInterceptor`] when redaction is enabled.
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie"];
+
+/// A hook that observes or mutates requests/responses flowing through an
+/// [`crate::OAuth2Client`].
+///
+/// Both methods default to no-ops so an interceptor only needs to implement
+/// the side it cares about. Registered interceptors run in registration
+/// order, for both `before` and `after`.
+#[async_trait::async_trait]
+pub trait Interceptor: Send + Sync {
+    /// Runs before the request is sent; may mutate it in place.
+    async fn before(&self, _request: &mut HttpRequest) {}
+
+    /// Runs after a response is received.
+    async fn after(&self, _request: &HttpRequest, _response: &HttpResponse) {}
+}
+
+/// Injects a fixed set of headers into every outgoing request, overwriting
+/// any existing value with the same name.
+///
+/// Useful for attaching a static `Authorization: Bearer ...` token, a
+/// tenant/id header, or any other header that should accompany every call.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderInjector {
+    headers: Vec<(http::HeaderName, http::HeaderValue)>,
+}
+
+impl HeaderInjector {
+    /// Creates an injector with no headers configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to insert on every request.
+    pub fn with_header(mut self, name: http::HeaderName, value: http::HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Interceptor for HeaderInjector {
+    async fn before(&self, request: &mut HttpRequest) {
+        for (name, value) in &self.headers {
+            request.headers_mut().insert(name.clone(), value.clone());
+        }
+    }
+}
+
+/// Emits `tracing` events describing the method, URI, and resulting status
+/// of each request, including the response body when the status is not a
+/// success (useful for debugging failed token exchanges).
+///
+/// Sensitive headers (`Authorization`, `Cookie`, `Set-Cookie`) and non-2xx
+/// response bodies are replaced with `"[redacted]"` by default so logs
+/// don't leak secrets; disable with [`TracingInterceptor::with_redaction`].
+#[derive(Debug, Clone)]
+pub struct TracingInterceptor {
+    redact: bool,
+}
+
+impl TracingInterceptor {
+    /// Creates a tracing interceptor with redaction enabled.
+    pub fn new() -> Self {
+        Self { redact: true }
+    }
+
+    /// Controls whether sensitive headers and non-2xx bodies are redacted.
+    pub fn with_redaction(mut self, redact: bool) -> Self {
+        self.redact = redact;
+        self
+    }
+
+    fn format_headers(&self, headers: &http::HeaderMap) -> String {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.redact && SENSITIVE_HEADERS.contains(&name.as_str()) {
+                    "[redacted]"
+                } else {
+                    value.to_str().unwrap_or("[non-utf8]")
+                };
+                format!("{}: {}", name, value)
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl Default for TracingInterceptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Interceptor for TracingInterceptor {
+    async fn before(&self, request: &mut HttpRequest) {
+        tracing::debug!(
+            method = %request.method(),
+            uri = %request.uri(),
+            headers = %self.format_headers(request.headers()),
+            "performing OAuth2 request"
+        );
+    }
+
+    async fn after(&self, request: &HttpRequest, response: &HttpResponse) {
+        let Some(error) = crate::OAuth2HttpError::from_response(response) else {
+            tracing::debug!(
+                method = %request.method(),
+                uri = %request.uri(),
+                status = %response.status(),
+                "OAuth2 request succeeded"
+            );
+            return;
+        };
+
+        let body = if self.redact {
+            "[redacted]".to_string()
+        } else {
+            String::from_utf8_lossy(&error.body).into_owned()
+        };
+        tracing::warn!(
+            method = %request.method(),
+            uri = %request.uri(),
+            status = %error.status,
+            error = error.parsed.as_ref().map(|e| e.error.as_str()).unwrap_or("unknown"),
+            %body,
+            "OAuth2 request failed"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_headers_redacts_sensitive_names() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(
+            http::header::AUTHORIZATION,
+            http::HeaderValue::from_static("Bearer secret"),
+        );
+        headers.insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+
+        let formatted = TracingInterceptor::new().format_headers(&headers);
+        assert!(formatted.contains("[redacted]"));
+        assert!(!formatted.contains("secret"));
+        assert!(formatted.contains("application/json"));
+    }
+}
@@ -10,6 +10,12 @@
 //! - Generic HTTP interface implementation
 //! - Type-safe error handling
 //! - Compatible with `oauth2` crate's `AsyncHttpClient` trait
+//! - Optional [`RetryingInterface`] decorator for exponential backoff retries
+//! - Optional per-request timeout via `OAuth2Client::with_timeout`
+//! - [`Authenticator`] for caching and auto-refreshing tokens across requests
+//! - Composable [`Interceptor`] chain for header injection and tracing
+//! - [`FastHttpInterface`] for an allocation-free, non-boxed fast path
+//! - [`OAuth2HttpError`] for structured, header- and body-aware error details
 //!
 //! # Example
 //!
@@ -24,10 +30,28 @@
 //! let oauth2_client = OAuth2Client::new(http_client);
 //! ```
 
+mod authenticator;
+mod error;
+mod fast;
+mod http_error;
+mod interceptor;
+mod retry;
 #[cfg(test)]
 mod test;
+mod token_store;
 
+pub use authenticator::{Authenticator, AuthenticatorError, TokenRefresher};
+pub use error::OAuth2ClientError;
+pub use fast::{FastHttpInterface, FastOAuth2Client};
+pub use http_error::{OAuth2ErrorBody, OAuth2HttpError};
+pub use interceptor::{HeaderInjector, Interceptor, TracingInterceptor};
+pub use retry::{RetryPolicy, RetryingInterface};
+pub use token_store::{FileTokenStore, FileTokenStoreError, InMemoryTokenStore, StoredToken, TokenKey, TokenStore};
+
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
 use oauth2::{AsyncHttpClient, HttpRequest, HttpResponse};
 
@@ -99,6 +123,12 @@ where
 {
     /// The underlying HTTP interface implementation
     interface: HI,
+    /// Overall deadline applied to each `call`, independent of the
+    /// underlying `HttpInterface`. `None` means no deadline is enforced.
+    timeout: Option<Duration>,
+    /// Hooks run before each request is sent and after its response arrives,
+    /// in registration order.
+    interceptors: Vec<Arc<dyn Interceptor>>,
 }
 
 impl<HI> OAuth2Client<HI>
@@ -121,7 +151,50 @@ where
     /// let oauth2_client = OAuth2Client::new(my_http_client);
     /// ```
     pub fn new(interface: HI) -> Self {
-        Self { interface }
+        Self {
+            interface,
+            timeout: None,
+            interceptors: Vec::new(),
+        }
+    }
+
+    /// Sets an overall deadline applied to every request performed through
+    /// this client, regardless of how the underlying `HttpInterface` is
+    /// implemented.
+    ///
+    /// Because this crate's entire purpose is abstracting over arbitrary
+    /// HTTP backends, the deadline lives here instead of requiring every
+    /// hand-rolled `HttpInterface` to configure its own timeout. If a
+    /// [`RetryingInterface`] is also in use, the deadline spans all of its
+    /// retries rather than each individual attempt.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use std::time::Duration;
+    /// use oauth2_http_client::OAuth2Client;
+    ///
+    /// let oauth2_client = OAuth2Client::new(my_http_client).with_timeout(Duration::from_secs(30));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Registers an interceptor to run on every request performed through
+    /// this client, after any interceptors already registered.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use oauth2_http_client::{OAuth2Client, TracingInterceptor};
+    ///
+    /// let oauth2_client = OAuth2Client::new(my_http_client)
+    ///     .with_interceptor(TracingInterceptor::new());
+    /// ```
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
     }
 }
 
@@ -130,7 +203,7 @@ where
     HI: HttpInterface + Clone + Send + Sync + 'static,
     HI::Error: std::error::Error,
 {
-    type Error = HI::Error;
+    type Error = OAuth2ClientError<HI::Error>;
 
     type Future = Pin<Box<dyn Future<Output = Result<HttpResponse, Self::Error>> + Send + 'c>>;
 
@@ -145,12 +218,33 @@ where
     ///
     /// # Returns
     ///
-    /// A pinned boxed future that resolves to the HTTP response or an error
-    fn call(&'c self, request: HttpRequest) -> Self::Future {
+    /// A pinned boxed future that resolves to the HTTP response or a
+    /// [`OAuth2ClientError`]
+    fn call(&'c self, mut request: HttpRequest) -> Self::Future {
         let interface = self.interface.clone();
+        let timeout = self.timeout;
+        let interceptors = self.interceptors.clone();
         Box::pin(async move {
-            let result = interface.perform(request).await?;
-            Ok(result)
+            for interceptor in &interceptors {
+                interceptor.before(&mut request).await;
+            }
+
+            let sent_request = request.clone();
+            let perform = interface.perform(request);
+            let result = match timeout {
+                Some(timeout) => tokio::time::timeout(timeout, perform)
+                    .await
+                    .map_err(|_| OAuth2ClientError::Timeout)?,
+                None => perform.await,
+            };
+
+            if let Ok(response) = &result {
+                for interceptor in &interceptors {
+                    interceptor.after(&sent_request, response).await;
+                }
+            }
+
+            result.map_err(OAuth2ClientError::Interface)
         })
     }
 }
@@ -0,0 +1,183 @@
+//! Retry decorator for [`HttpInterface`] implementations.
+//!
+//! [`RetryingInterface`] wraps any [`HttpInterface`] and transparently retries
+//! failed requests using exponential backoff with full jitter, honoring the
+//! `Retry-After` response header (and the OAuth2-style `retry_after_ms` JSON
+//! body field) when the server provides one. This is primarily useful for
+//! device-code polling and token endpoints that rate-limit aggressively.
+
+use std::time::Duration;
+
+use oauth2::{HttpRequest, HttpResponse};
+use rand::RngExt;
+
+use crate::http_error::is_retryable_status;
+use crate::HttpInterface;
+
+/// Configuration for the exponential backoff used by [`RetryingInterface`].
+///
+/// On each failed attempt the delay is computed as
+/// `min(max_delay, base_delay * multiplier^attempt)`, then a random jitter in
+/// `[0, delay]` is applied (full jitter) before sleeping and retrying.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Delay used for the first retry, before backoff is applied.
+    pub base_delay: Duration,
+    /// Multiplier applied to the delay after each failed attempt.
+    pub multiplier: f64,
+    /// Upper bound on the computed delay, before jitter.
+    pub max_delay: Duration,
+    /// Total number of attempts (including the first), after which the last
+    /// error or response is returned.
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Computes the exponential backoff delay for the given zero-based
+    /// attempt, with full jitter already applied.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let capped = (self.base_delay.as_secs_f64() * self.multiplier.powi(attempt as i32))
+            .min(self.max_delay.as_secs_f64())
+            .max(0.0);
+        let jittered = rand::rng().random_range(0.0..=capped);
+        Duration::from_secs_f64(jittered)
+    }
+}
+
+/// Decorates an [`HttpInterface`] with automatic retries.
+///
+/// Transport errors and responses with a retryable status code (429 or
+/// 500–504) are retried until [`RetryPolicy::max_attempts`] attempts, the
+/// first included, have been made.
+/// Requests are buffered `http::Request<Vec<u8>>` bodies, so they can be
+/// cloned and resent without re-reading from the caller.
+///
+/// # Example
+///
+/// ```ignore
+/// use oauth2_http_client::{OAuth2Client, RetryPolicy, RetryingInterface};
+///
+/// let interface = RetryingInterface::new(my_http_interface, RetryPolicy::default());
+/// let oauth2_client = OAuth2Client::new(interface);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryingInterface<HI> {
+    interface: HI,
+    policy: RetryPolicy,
+}
+
+impl<HI> RetryingInterface<HI> {
+    /// Wraps `interface` so that every request is retried according to `policy`.
+    pub fn new(interface: HI, policy: RetryPolicy) -> Self {
+        Self { interface, policy }
+    }
+}
+
+/// Extracts a server-requested retry delay from `response`, if any.
+///
+/// Checks the `Retry-After` header first (both delta-seconds and HTTP-date
+/// forms), then falls back to a `retry_after_ms` field in a JSON response
+/// body.
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    if let Some(header) = response.headers().get(http::header::RETRY_AFTER) {
+        if let Ok(value) = header.to_str() {
+            if let Ok(seconds) = value.trim().parse::<u64>() {
+                return Some(Duration::from_secs(seconds));
+            }
+            if let Ok(when) = httpdate::parse_http_date(value.trim()) {
+                return when.duration_since(std::time::SystemTime::now()).ok();
+            }
+        }
+    }
+
+    serde_json::from_slice::<serde_json::Value>(response.body())
+        .ok()
+        .and_then(|json| json.get("retry_after_ms")?.as_u64())
+        .map(Duration::from_millis)
+}
+
+#[async_trait::async_trait]
+impl<HI> HttpInterface for RetryingInterface<HI>
+where
+    HI: HttpInterface + Send + Sync,
+{
+    type Error = HI::Error;
+
+    async fn perform(&self, req: HttpRequest) -> Result<HttpResponse, Self::Error> {
+        let mut attempt = 0;
+        loop {
+            let result = self.interface.perform(req.clone()).await;
+            let is_last_attempt = attempt + 1 >= self.policy.max_attempts;
+
+            let delay = match &result {
+                Ok(response) if is_retryable_status(response.status()) && !is_last_attempt => {
+                    retry_after(response).unwrap_or_else(|| self.policy.backoff_delay(attempt))
+                }
+                Err(_) if !is_last_attempt => self.policy.backoff_delay(attempt),
+                _ => return result,
+            };
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_is_capped_at_max_delay() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(1),
+            max_attempts: 5,
+        };
+
+        for attempt in 0..10 {
+            assert!(policy.backoff_delay(attempt) <= policy.max_delay);
+        }
+    }
+
+    #[test]
+    fn retry_after_prefers_delta_seconds_header() {
+        let response = http::Response::builder()
+            .status(429)
+            .header("Retry-After", "2")
+            .body(serde_json::to_vec(&serde_json::json!({ "retry_after_ms": 250 })).unwrap())
+            .unwrap();
+
+        assert_eq!(retry_after(&response), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn retry_after_falls_back_to_json_body_field() {
+        let response = http::Response::builder()
+            .status(429)
+            .body(serde_json::to_vec(&serde_json::json!({ "retry_after_ms": 250 })).unwrap())
+            .unwrap();
+
+        assert_eq!(retry_after(&response), Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn only_specific_statuses_are_retryable() {
+        assert!(is_retryable_status(http::StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(http::StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(http::StatusCode::BAD_REQUEST));
+        assert!(!is_retryable_status(http::StatusCode::OK));
+    }
+}
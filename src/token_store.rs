@@ -0,0 +1,268 @@
+//! Persistence for cached OAuth2 tokens.
+//!
+//! [`TokenStore`] is the storage contract used by [`crate::Authenticator`] to
+//! cache access tokens between requests. [`InMemoryTokenStore`] and
+//! [`FileTokenStore`] cover the common cases; remote or secret-manager backed
+//! stores can implement the trait directly since it is async.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+/// Identifies a cached token by the client id and scopes it was issued for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TokenKey {
+    /// The OAuth2 client id the token was issued to.
+    pub client_id: String,
+    /// The scopes the token was issued for.
+    pub scopes: Vec<String>,
+}
+
+impl TokenKey {
+    /// Creates a new key from a client id and its scopes.
+    pub fn new(client_id: impl Into<String>, scopes: Vec<String>) -> Self {
+        Self {
+            client_id: client_id.into(),
+            scopes,
+        }
+    }
+
+    /// A stable string form of this key, suitable for use as a map key when
+    /// persisting to storage that requires string keys (e.g. JSON).
+    fn cache_key(&self) -> String {
+        format!("{}::{}", self.client_id, self.scopes.join(","))
+    }
+}
+
+/// An access token cached alongside its expiry deadline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredToken {
+    /// The access token value.
+    pub access_token: String,
+    /// The refresh token, if the server issued one.
+    pub refresh_token: Option<String>,
+    /// The instant at which `access_token` stops being valid.
+    pub expires_at: SystemTime,
+}
+
+impl StoredToken {
+    /// Builds a `StoredToken` whose `expires_at` is derived from the
+    /// token response's `expires_in` duration.
+    pub fn new(access_token: String, refresh_token: Option<String>, expires_in: Duration) -> Self {
+        Self {
+            access_token,
+            refresh_token,
+            expires_at: SystemTime::now() + expires_in,
+        }
+    }
+
+    /// Returns `true` if this token is still valid at least `skew` before
+    /// its actual expiry.
+    pub fn is_valid(&self, skew: Duration) -> bool {
+        match self.expires_at.checked_sub(skew) {
+            Some(refresh_at) => SystemTime::now() < refresh_at,
+            None => false,
+        }
+    }
+}
+
+/// Storage for cached [`StoredToken`]s, keyed by [`TokenKey`].
+///
+/// Implementations must be safe to share across tasks; [`crate::Authenticator`]
+/// calls `load` before every request and `store` after every refresh.
+#[async_trait::async_trait]
+pub trait TokenStore {
+    /// The error type returned when storage access fails.
+    type Error: std::fmt::Debug + Send + Sync + 'static;
+
+    /// Loads the cached token for `key`, if one exists.
+    async fn load(&self, key: &TokenKey) -> Result<Option<StoredToken>, Self::Error>;
+
+    /// Persists `token` under `key`, replacing any previous value.
+    async fn store(&self, key: &TokenKey, token: StoredToken) -> Result<(), Self::Error>;
+
+    /// Removes any cached token for `key`.
+    async fn delete(&self, key: &TokenKey) -> Result<(), Self::Error>;
+}
+
+/// A [`TokenStore`] that keeps tokens in memory for the lifetime of the process.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTokenStore {
+    tokens: Arc<Mutex<HashMap<TokenKey, StoredToken>>>,
+}
+
+impl InMemoryTokenStore {
+    /// Creates an empty in-memory token store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for InMemoryTokenStore {
+    type Error = std::convert::Infallible;
+
+    async fn load(&self, key: &TokenKey) -> Result<Option<StoredToken>, Self::Error> {
+        Ok(self.tokens.lock().await.get(key).cloned())
+    }
+
+    async fn store(&self, key: &TokenKey, token: StoredToken) -> Result<(), Self::Error> {
+        self.tokens.lock().await.insert(key.clone(), token);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &TokenKey) -> Result<(), Self::Error> {
+        self.tokens.lock().await.remove(key);
+        Ok(())
+    }
+}
+
+/// Error returned by [`FileTokenStore`].
+#[derive(Debug)]
+pub enum FileTokenStoreError {
+    /// Reading or writing the backing file failed.
+    Io(std::io::Error),
+    /// The backing file did not contain valid token data.
+    Serde(serde_json::Error),
+}
+
+impl std::fmt::Display for FileTokenStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FileTokenStoreError::Io(err) => write!(f, "{}", err),
+            FileTokenStoreError::Serde(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for FileTokenStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FileTokenStoreError::Io(err) => Some(err),
+            FileTokenStoreError::Serde(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for FileTokenStoreError {
+    fn from(err: std::io::Error) -> Self {
+        FileTokenStoreError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for FileTokenStoreError {
+    fn from(err: serde_json::Error) -> Self {
+        FileTokenStoreError::Serde(err)
+    }
+}
+
+/// A [`TokenStore`] that persists all cached tokens as a single JSON file.
+///
+/// Reads and writes are serialized through an internal lock so concurrent
+/// callers don't race on the file, but the store does not attempt to
+/// coordinate across separate processes.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+    lock: Arc<Mutex<()>>,
+}
+
+impl FileTokenStore {
+    /// Creates a store backed by the JSON file at `path`. The file is
+    /// created on the first `store` call if it does not already exist.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    async fn read_all(&self) -> Result<HashMap<String, StoredToken>, FileTokenStoreError> {
+        match tokio::fs::read(&self.path).await {
+            Ok(bytes) if bytes.is_empty() => Ok(HashMap::new()),
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn write_all(
+        &self,
+        tokens: &HashMap<String, StoredToken>,
+    ) -> Result<(), FileTokenStoreError> {
+        let bytes = serde_json::to_vec_pretty(tokens)?;
+        tokio::fs::write(&self.path, bytes).await?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for FileTokenStore {
+    type Error = FileTokenStoreError;
+
+    async fn load(&self, key: &TokenKey) -> Result<Option<StoredToken>, Self::Error> {
+        let _guard = self.lock.lock().await;
+        Ok(self.read_all().await?.remove(&key.cache_key()))
+    }
+
+    async fn store(&self, key: &TokenKey, token: StoredToken) -> Result<(), Self::Error> {
+        let _guard = self.lock.lock().await;
+        let mut tokens = self.read_all().await?;
+        tokens.insert(key.cache_key(), token);
+        self.write_all(&tokens).await
+    }
+
+    async fn delete(&self, key: &TokenKey) -> Result<(), Self::Error> {
+        let _guard = self.lock.lock().await;
+        let mut tokens = self.read_all().await?;
+        tokens.remove(&key.cache_key());
+        self.write_all(&tokens).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_token_is_valid_respects_skew() {
+        let token = StoredToken::new("access".into(), None, Duration::from_secs(120));
+        assert!(token.is_valid(Duration::from_secs(60)));
+        assert!(!token.is_valid(Duration::from_secs(600)));
+    }
+
+    #[tokio::test]
+    async fn in_memory_store_round_trips() {
+        let store = InMemoryTokenStore::new();
+        let key = TokenKey::new("client", vec!["scope1".into()]);
+        assert_eq!(store.load(&key).await.unwrap(), None);
+
+        let token = StoredToken::new("access".into(), Some("refresh".into()), Duration::from_secs(60));
+        store.store(&key, token.clone()).await.unwrap();
+        assert_eq!(store.load(&key).await.unwrap(), Some(token));
+
+        store.delete(&key).await.unwrap();
+        assert_eq!(store.load(&key).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn file_store_round_trips() {
+        let dir = std::env::temp_dir().join(format!(
+            "oauth2_http_client_test_{:?}",
+            std::thread::current().id()
+        ));
+        let path = dir.with_extension("json");
+        let store = FileTokenStore::new(&path);
+        let key = TokenKey::new("client", vec!["scope1".into(), "scope2".into()]);
+
+        let token = StoredToken::new("access".into(), None, Duration::from_secs(60));
+        store.store(&key, token.clone()).await.unwrap();
+        assert_eq!(store.load(&key).await.unwrap(), Some(token));
+
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+}